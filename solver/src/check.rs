@@ -0,0 +1,314 @@
+use std::collections::HashMap;
+use std::fs;
+use std::path::Path;
+
+use crate::export::{OperationResult, ScheduleResult};
+use crate::problem::JsspInstance;
+
+/// Every constraint violation found while validating a schedule.
+#[derive(Debug, Default)]
+pub struct ViolationReport {
+    pub violations: Vec<String>,
+}
+
+impl ViolationReport {
+    fn push(&mut self, message: String) {
+        self.violations.push(message);
+    }
+
+    pub fn is_feasible(&self) -> bool {
+        self.violations.is_empty()
+    }
+}
+
+pub fn load_schedule(path: &Path) -> Result<ScheduleResult, String> {
+    let content = fs::read_to_string(path)
+        .map_err(|err| format!("failed to read schedule file {path:?}: {err}"))?;
+
+    serde_json::from_str(&content)
+        .map_err(|err| format!("failed to parse schedule file {path:?}: {err}"))
+}
+
+/// Validates `schedule` against `instance`: it covers every instance
+/// operation exactly once, every operation's processing time and
+/// machine/job assignment match the instance, job precedence is respected,
+/// no two operations on the same machine overlap, and the reported
+/// makespan equals the recomputed one.
+pub fn validate(instance: &JsspInstance, schedule: &ScheduleResult) -> ViolationReport {
+    let mut report = ViolationReport::default();
+
+    let ops_by_id: HashMap<usize, _> = instance.operations.iter().map(|op| (op.id, op)).collect();
+
+    check_completeness(instance, schedule, &mut report);
+
+    for op in &schedule.operations {
+        match ops_by_id.get(&op.operation_id) {
+            Some(instance_op) => {
+                let expected_duration = instance_op.duration;
+                let actual_duration = op.finish.saturating_sub(op.start);
+                if actual_duration != expected_duration {
+                    report.push(format!(
+                        "operation {}: processing time {actual_duration} does not match instance's {expected_duration}",
+                        op.operation_id
+                    ));
+                }
+                if instance_op.job_id != op.job_id {
+                    report.push(format!(
+                        "operation {}: job id {} does not match instance job id {}",
+                        op.operation_id, op.job_id, instance_op.job_id
+                    ));
+                }
+                if instance_op.machine_id != op.machine_id {
+                    report.push(format!(
+                        "operation {}: machine id {} does not match instance machine id {}",
+                        op.operation_id, op.machine_id, instance_op.machine_id
+                    ));
+                }
+            }
+            None => report.push(format!(
+                "operation {} does not exist in the instance",
+                op.operation_id
+            )),
+        }
+    }
+
+    check_job_precedence(schedule, &mut report);
+    check_machine_exclusivity(schedule, &mut report);
+
+    let recomputed_makespan = schedule
+        .operations
+        .iter()
+        .map(|op| op.finish)
+        .max()
+        .unwrap_or(0);
+    if recomputed_makespan != schedule.makespan {
+        report.push(format!(
+            "reported makespan {} does not match recomputed makespan {recomputed_makespan}",
+            schedule.makespan
+        ));
+    }
+
+    report
+}
+
+/// Every instance operation must appear in the schedule exactly once;
+/// otherwise the precedence/machine-overlap checks below would silently
+/// pass over the missing operations and a truncated schedule could be
+/// reported as feasible.
+fn check_completeness(
+    instance: &JsspInstance,
+    schedule: &ScheduleResult,
+    report: &mut ViolationReport,
+) {
+    let mut seen_count: HashMap<usize, usize> = HashMap::new();
+    for op in &schedule.operations {
+        *seen_count.entry(op.operation_id).or_insert(0) += 1;
+    }
+
+    for op in &instance.operations {
+        match seen_count.get(&op.id).copied().unwrap_or(0) {
+            0 => report.push(format!("operation {} is missing from the schedule", op.id)),
+            1 => {}
+            count => report.push(format!(
+                "operation {} appears {count} times in the schedule, expected once",
+                op.id
+            )),
+        }
+    }
+}
+
+/// Operations within a job are numbered in the order they were defined, so
+/// sorting by operation id recovers precedence order.
+fn check_job_precedence(schedule: &ScheduleResult, report: &mut ViolationReport) {
+    let mut ops_by_job: HashMap<usize, Vec<&OperationResult>> = HashMap::new();
+    for op in &schedule.operations {
+        ops_by_job.entry(op.job_id).or_default().push(op);
+    }
+
+    for (job_id, mut ops) in ops_by_job {
+        ops.sort_by_key(|op| op.operation_id);
+        for pair in ops.windows(2) {
+            if pair[1].start < pair[0].finish {
+                report.push(format!(
+                    "job {job_id}: operation {} starts at {} before preceding operation {} finishes at {}",
+                    pair[1].operation_id, pair[1].start, pair[0].operation_id, pair[0].finish
+                ));
+            }
+        }
+    }
+}
+
+fn check_machine_exclusivity(schedule: &ScheduleResult, report: &mut ViolationReport) {
+    let mut ops_by_machine: HashMap<usize, Vec<&OperationResult>> = HashMap::new();
+    for op in &schedule.operations {
+        ops_by_machine.entry(op.machine_id).or_default().push(op);
+    }
+
+    for (machine_id, mut ops) in ops_by_machine {
+        ops.sort_by_key(|op| op.start);
+        for pair in ops.windows(2) {
+            if pair[1].start < pair[0].finish {
+                report.push(format!(
+                    "machine {machine_id}: operation {} overlaps operation {}",
+                    pair[1].operation_id, pair[0].operation_id
+                ));
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::problem::{JsspConfig, Operation};
+
+    // Two jobs, two machines: job 0 = [op0 on M0 dur 2, op1 on M1 dur 3],
+    // job 1 = [op2 on M1 dur 4, op3 on M0 dur 1].
+    fn instance() -> JsspInstance {
+        JsspInstance {
+            cfg: JsspConfig {
+                n_jobs: 2,
+                n_machines: 2,
+                n_ops: 4,
+            },
+            operations: vec![
+                Operation {
+                    id: 0,
+                    job_id: 0,
+                    machine_id: 0,
+                    duration: 2,
+                },
+                Operation {
+                    id: 1,
+                    job_id: 0,
+                    machine_id: 1,
+                    duration: 3,
+                },
+                Operation {
+                    id: 2,
+                    job_id: 1,
+                    machine_id: 1,
+                    duration: 4,
+                },
+                Operation {
+                    id: 3,
+                    job_id: 1,
+                    machine_id: 0,
+                    duration: 1,
+                },
+            ],
+        }
+    }
+
+    fn op(
+        operation_id: usize,
+        job_id: usize,
+        machine_id: usize,
+        start: usize,
+        finish: usize,
+    ) -> OperationResult {
+        OperationResult {
+            operation_id,
+            job_id,
+            machine_id,
+            start,
+            finish,
+        }
+    }
+
+    #[test]
+    fn accepts_a_feasible_schedule() {
+        let schedule = ScheduleResult {
+            makespan: 7,
+            operations: vec![
+                op(0, 0, 0, 0, 2),
+                op(1, 0, 1, 4, 7),
+                op(2, 1, 1, 0, 4),
+                op(3, 1, 0, 4, 5),
+            ],
+            machines: vec![],
+        };
+
+        let report = validate(&instance(), &schedule);
+        assert!(report.is_feasible(), "{:?}", report.violations);
+    }
+
+    #[test]
+    fn rejects_a_job_precedence_violation() {
+        // op1 runs (0-3) before its own predecessor op0 (3-5) has started.
+        let schedule = ScheduleResult {
+            makespan: 8,
+            operations: vec![
+                op(1, 0, 1, 0, 3),
+                op(0, 0, 0, 3, 5),
+                op(2, 1, 1, 0, 4),
+                op(3, 1, 0, 4, 5),
+            ],
+            machines: vec![],
+        };
+
+        let report = validate(&instance(), &schedule);
+        assert!(!report.is_feasible());
+        assert!(report
+            .violations
+            .iter()
+            .any(|v| v.contains("before preceding operation")));
+    }
+
+    #[test]
+    fn rejects_a_machine_overlap() {
+        // op0 and op3 both claim machine 0 at overlapping times.
+        let schedule = ScheduleResult {
+            makespan: 5,
+            operations: vec![
+                op(0, 0, 0, 0, 2),
+                op(1, 0, 1, 2, 5),
+                op(2, 1, 1, 0, 4),
+                op(3, 1, 0, 1, 2),
+            ],
+            machines: vec![],
+        };
+
+        let report = validate(&instance(), &schedule);
+        assert!(!report.is_feasible());
+        assert!(report.violations.iter().any(|v| v.contains("overlaps")));
+    }
+
+    #[test]
+    fn rejects_a_schedule_missing_an_operation() {
+        // op3 is dropped entirely; the remaining three still line up fine.
+        let schedule = ScheduleResult {
+            makespan: 7,
+            operations: vec![op(0, 0, 0, 0, 2), op(1, 0, 1, 4, 7), op(2, 1, 1, 0, 4)],
+            machines: vec![],
+        };
+
+        let report = validate(&instance(), &schedule);
+        assert!(!report.is_feasible());
+        assert!(report
+            .violations
+            .iter()
+            .any(|v| v.contains("operation 3 is missing")));
+    }
+
+    #[test]
+    fn rejects_a_schedule_with_a_duplicated_operation() {
+        let schedule = ScheduleResult {
+            makespan: 5,
+            operations: vec![
+                op(0, 0, 0, 0, 2),
+                op(1, 0, 1, 2, 5),
+                op(2, 1, 1, 0, 4),
+                op(2, 1, 1, 0, 4),
+            ],
+            machines: vec![],
+        };
+
+        let report = validate(&instance(), &schedule);
+        assert!(!report.is_feasible());
+        assert!(report
+            .violations
+            .iter()
+            .any(|v| v.contains("operation 2 appears 2 times")));
+    }
+}
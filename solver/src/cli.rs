@@ -0,0 +1,99 @@
+use std::path::PathBuf;
+
+use clap::{Parser, Subcommand};
+
+use crate::parse::Format;
+
+#[derive(Parser, Debug)]
+#[command(author, version, about = "ecrs-data-kit JSSP solver")]
+pub struct Cli {
+    #[command(subcommand)]
+    pub command: Command,
+}
+
+#[derive(Subcommand, Debug)]
+pub enum Command {
+    /// Run the GA solver against an instance
+    Solve(SolveArgs),
+    /// Validate a schedule file against an instance without running the GA
+    Check(CheckArgs),
+}
+
+#[derive(clap::Args, Debug)]
+pub struct SolveArgs {
+    /// Path to the problem instance file
+    #[arg(short, long)]
+    pub input_file: PathBuf,
+
+    /// Layout of the instance file; `auto` sniffs it from the file's
+    /// contents
+    #[arg(long, value_enum, default_value = "auto")]
+    pub format: Format,
+
+    /// Directory for run output; `run_metadata.json` is created here
+    #[arg(short, long, default_value = "output")]
+    pub output_dir: PathBuf,
+
+    /// Which solver variant to run
+    #[arg(long, default_value = "paper")]
+    pub solver_type: String,
+
+    /// Override the population size derived from the instance
+    #[arg(long)]
+    pub pop_size: Option<usize>,
+
+    /// Override the number of generations the GA is allowed to run for
+    #[arg(long)]
+    pub n_gen: Option<usize>,
+
+    /// Stop early once the coefficient of variation of the best fitness over
+    /// `--cv-window` generations falls below this threshold
+    #[arg(long)]
+    pub min_cv: Option<f64>,
+
+    /// Size of the sliding window used to compute the convergence coefficient
+    /// of variation
+    #[arg(long, default_value_t = 100)]
+    pub cv_window: usize,
+
+    /// Seed the initial population from a previously produced schedule
+    /// (the chromosome encoding used by `JsspIndividual`, one operation id
+    /// per line)
+    #[arg(long)]
+    pub init_solution: Option<PathBuf>,
+
+    /// Number of copies/perturbations of `--init-solution` to inject into
+    /// the initial population; ignored if `--init-solution` is absent
+    #[arg(long, default_value_t = 1)]
+    pub init_size: usize,
+
+    /// Write the best schedule found (per-operation timings, machine
+    /// assignment and the final makespan) as JSON to this path
+    #[arg(long)]
+    pub out_result: Option<PathBuf>,
+
+    /// Build the solver from a declarative experiment config (TOML/JSON)
+    /// instead of `--solver-type`'s hardcoded operator combinations
+    #[arg(long)]
+    pub config: Option<PathBuf>,
+}
+
+#[derive(clap::Args, Debug)]
+pub struct CheckArgs {
+    /// Path to the problem instance file the schedule claims to solve
+    #[arg(short, long)]
+    pub input_file: PathBuf,
+
+    /// Layout of the instance file; `auto` sniffs it from the file's
+    /// contents
+    #[arg(long, value_enum, default_value = "auto")]
+    pub format: Format,
+
+    /// Path to the schedule file, as produced by `solve --out-result`
+    #[arg(short, long)]
+    pub schedule_file: PathBuf,
+}
+
+pub fn parse_args() -> Cli {
+    Cli::parse()
+}
@@ -0,0 +1,90 @@
+use std::path::PathBuf;
+
+use crate::cli::SolveArgs;
+use crate::experiment::ExperimentConfig;
+use crate::parse::Format;
+
+pub const SOLVER_TYPE_RANDOMSEARCH: &str = "randomsearch";
+pub const SOLVER_TYPE_CUSTOM_CROSSOVER: &str = "custom-crossover";
+pub const SOLVER_TYPE_PAPER: &str = "paper";
+
+#[derive(Debug, Clone)]
+pub struct Config {
+    pub input_file: PathBuf,
+    pub format: Format,
+    pub output_dir: PathBuf,
+    pub solver_type: String,
+    pub pop_size: Option<usize>,
+    pub n_gen: Option<usize>,
+
+    /// Coefficient-of-variation threshold for convergence-based early
+    /// stopping. `None` disables the criterion entirely.
+    pub min_cv: Option<f64>,
+
+    /// Window size (in generations) over which the coefficient of variation
+    /// is computed.
+    pub cv_window: usize,
+
+    /// Warm-start solution to seed the initial population from, if any.
+    pub init_solution: Option<PathBuf>,
+
+    /// How many copies/perturbations of `init_solution` to inject into the
+    /// initial population.
+    pub init_size: usize,
+
+    /// Where to write the best schedule found, as JSON. `None` means no
+    /// schedule file is written.
+    pub out_result: Option<PathBuf>,
+
+    /// Declarative operator/parameter description loaded from `--config`,
+    /// if any. When present it takes priority over `solver_type`.
+    pub experiment: Option<ExperimentConfig>,
+}
+
+impl TryFrom<SolveArgs> for Config {
+    type Error = String;
+
+    fn try_from(args: SolveArgs) -> Result<Self, Self::Error> {
+        if !args.input_file.exists() {
+            return Err(format!("input file {:?} does not exist", args.input_file));
+        }
+
+        if let Some(min_cv) = args.min_cv {
+            if min_cv <= 0.0 {
+                return Err(format!("--min-cv must be positive, got {min_cv}"));
+            }
+        }
+
+        if args.cv_window == 0 {
+            return Err(String::from("--cv-window must be non-zero"));
+        }
+
+        if let Some(init_solution) = &args.init_solution {
+            if !init_solution.exists() {
+                return Err(format!(
+                    "--init-solution file {init_solution:?} does not exist"
+                ));
+            }
+        }
+
+        let experiment = match &args.config {
+            Some(path) => Some(crate::experiment::load(path)?),
+            None => None,
+        };
+
+        Ok(Config {
+            input_file: args.input_file,
+            format: args.format,
+            output_dir: args.output_dir,
+            solver_type: args.solver_type,
+            pop_size: args.pop_size,
+            n_gen: args.n_gen,
+            min_cv: args.min_cv,
+            cv_window: args.cv_window,
+            init_solution: args.init_solution,
+            init_size: args.init_size,
+            out_result: args.out_result,
+            experiment,
+        })
+    }
+}
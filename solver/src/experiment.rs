@@ -0,0 +1,189 @@
+use std::collections::HashMap;
+use std::fs;
+use std::path::Path;
+
+use ecrs::prelude::crossover::CrossoverOperator;
+use ecrs::prelude::mutation::{self, MutationOperator};
+use ecrs::prelude::replacement::ReplacementOperator;
+use ecrs::prelude::selection::{self, SelectionOperator};
+use serde::Deserialize;
+
+use crate::problem::crossover::{JsspCrossover, MidPoint, NoopCrossover};
+use crate::problem::fitness::JsspFitness;
+use crate::problem::individual::JsspIndividual;
+use crate::problem::population::JsspPopProvider;
+use crate::problem::replacement::{JsspReplacement, ReplaceWithRandomPopulation};
+use crate::problem::selection::EmptySelection;
+use crate::problem::JsspInstance;
+
+/// Names an operator and the numeric parameters it was configured with.
+#[derive(Debug, Clone, Deserialize)]
+pub struct OperatorSpec {
+    pub name: String,
+    #[serde(default)]
+    pub params: HashMap<String, f64>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct FitnessSpec {
+    pub tightness: f64,
+}
+
+/// A declarative description of a solver configuration: which operators to
+/// use and their numeric parameters. Either read from a user-supplied
+/// TOML/JSON file via [`load`], or looked up as a [`built_in`] preset from
+/// `--solver-type`; either way the solver is built through the same
+/// [`build_selection`]/[`build_crossover`]/[`build_mutation`]/
+/// [`build_replacement`]/[`build_fitness`] path.
+#[derive(Debug, Clone, Deserialize)]
+pub struct ExperimentConfig {
+    pub selection: OperatorSpec,
+    pub crossover: OperatorSpec,
+    pub mutation: OperatorSpec,
+    pub replacement: OperatorSpec,
+    pub fitness: FitnessSpec,
+}
+
+/// The built-in operator combinations that used to be hardcoded into
+/// dedicated `run_*` functions, expressed as [`ExperimentConfig`] presets so
+/// `--solver-type` and `--config` both route through the same builder path.
+pub fn built_in(solver_type: &str) -> ExperimentConfig {
+    match solver_type {
+        crate::config::SOLVER_TYPE_RANDOMSEARCH => randomsearch_preset(),
+        crate::config::SOLVER_TYPE_CUSTOM_CROSSOVER => custom_crossover_preset(),
+        _ => paper_preset(),
+    }
+}
+
+fn operator(name: &str) -> OperatorSpec {
+    OperatorSpec {
+        name: String::from(name),
+        params: HashMap::new(),
+    }
+}
+
+fn jssp_replacement() -> OperatorSpec {
+    OperatorSpec {
+        name: String::from("jssp"),
+        params: HashMap::from([
+            (String::from("elitism_rate"), 0.1),
+            (String::from("immigration_rate"), 0.2),
+        ]),
+    }
+}
+
+fn paper_preset() -> ExperimentConfig {
+    ExperimentConfig {
+        selection: operator("rank"),
+        crossover: operator("jssp"),
+        mutation: operator("identity"),
+        replacement: jssp_replacement(),
+        fitness: FitnessSpec { tightness: 1.5 },
+    }
+}
+
+fn randomsearch_preset() -> ExperimentConfig {
+    ExperimentConfig {
+        selection: operator("empty"),
+        crossover: operator("noop"),
+        mutation: operator("identity"),
+        replacement: operator("random"),
+        fitness: FitnessSpec { tightness: 1.5 },
+    }
+}
+
+fn custom_crossover_preset() -> ExperimentConfig {
+    ExperimentConfig {
+        selection: operator("rank"),
+        crossover: operator("midpoint"),
+        mutation: operator("identity"),
+        replacement: jssp_replacement(),
+        fitness: FitnessSpec { tightness: 1.5 },
+    }
+}
+
+/// Reads an [`ExperimentConfig`] from `path`, deserializing as TOML unless
+/// the extension is `.json`.
+pub fn load(path: &Path) -> Result<ExperimentConfig, String> {
+    let content = fs::read_to_string(path)
+        .map_err(|err| format!("failed to read experiment config {path:?}: {err}"))?;
+
+    match path.extension().and_then(|ext| ext.to_str()) {
+        Some("json") => serde_json::from_str(&content)
+            .map_err(|err| format!("invalid JSON experiment config {path:?}: {err}")),
+        _ => toml::from_str(&content)
+            .map_err(|err| format!("invalid TOML experiment config {path:?}: {err}")),
+    }
+}
+
+fn probability(spec: &OperatorSpec, key: &str, default: f64) -> Result<f64, String> {
+    let value = *spec.params.get(key).unwrap_or(&default);
+    if !(0.0..=1.0).contains(&value) {
+        return Err(format!(
+            "{}: {key} must be within [0, 1], got {value}",
+            spec.name
+        ));
+    }
+    Ok(value)
+}
+
+pub fn build_selection(
+    spec: &OperatorSpec,
+) -> Result<Box<dyn SelectionOperator<JsspIndividual>>, String> {
+    match spec.name.as_str() {
+        "rank" => Ok(Box::new(selection::Rank::new())),
+        "empty" => Ok(Box::new(EmptySelection::new())),
+        other => Err(format!("unknown selection operator {other:?}")),
+    }
+}
+
+pub fn build_crossover(
+    spec: &OperatorSpec,
+) -> Result<Box<dyn CrossoverOperator<JsspIndividual>>, String> {
+    match spec.name.as_str() {
+        "jssp" => Ok(Box::new(JsspCrossover::new())),
+        "midpoint" => Ok(Box::new(MidPoint::new())),
+        "noop" => Ok(Box::new(NoopCrossover::new())),
+        other => Err(format!("unknown crossover operator {other:?}")),
+    }
+}
+
+pub fn build_mutation(
+    spec: &OperatorSpec,
+) -> Result<Box<dyn MutationOperator<JsspIndividual>>, String> {
+    match spec.name.as_str() {
+        "identity" => Ok(Box::new(mutation::Identity::new())),
+        other => Err(format!("unknown mutation operator {other:?}")),
+    }
+}
+
+pub fn build_replacement(
+    spec: &OperatorSpec,
+    instance: &JsspInstance,
+) -> Result<Box<dyn ReplacementOperator<JsspIndividual>>, String> {
+    match spec.name.as_str() {
+        "jssp" => {
+            let elitism_rate = probability(spec, "elitism_rate", 0.1)?;
+            let immigration_rate = probability(spec, "immigration_rate", 0.2)?;
+            Ok(Box::new(JsspReplacement::new(
+                JsspPopProvider::new(instance.clone()),
+                elitism_rate,
+                immigration_rate,
+            )))
+        }
+        "random" => Ok(Box::new(ReplaceWithRandomPopulation::new(
+            JsspPopProvider::new(instance.clone()),
+        ))),
+        other => Err(format!("unknown replacement operator {other:?}")),
+    }
+}
+
+pub fn build_fitness(spec: &FitnessSpec) -> Result<JsspFitness, String> {
+    if spec.tightness <= 0.0 {
+        return Err(format!(
+            "fitness tightness must be positive, got {}",
+            spec.tightness
+        ));
+    }
+    Ok(JsspFitness::new(spec.tightness))
+}
@@ -0,0 +1,147 @@
+use std::fs;
+use std::path::Path;
+
+use serde::{Deserialize, Serialize};
+
+use crate::problem::fitness::decode;
+use crate::problem::individual::JsspIndividual;
+
+#[derive(Serialize, Deserialize)]
+pub struct OperationResult {
+    pub operation_id: usize,
+    pub job_id: usize,
+    pub machine_id: usize,
+    pub start: usize,
+    pub finish: usize,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct MachineSchedule {
+    pub machine_id: usize,
+    /// Operation ids on this machine, in execution order.
+    pub operations: Vec<usize>,
+}
+
+/// The exported representation of a solved schedule: enough to render a
+/// Gantt chart, or to be re-validated by the `check` subcommand without
+/// re-running the GA.
+#[derive(Serialize, Deserialize)]
+pub struct ScheduleResult {
+    pub makespan: usize,
+    pub operations: Vec<OperationResult>,
+    pub machines: Vec<MachineSchedule>,
+}
+
+impl From<&JsspIndividual> for ScheduleResult {
+    fn from(individual: &JsspIndividual) -> Self {
+        let decoded = decode(individual);
+
+        let operations = decoded
+            .operations
+            .iter()
+            .map(|op| OperationResult {
+                operation_id: op.operation_id,
+                job_id: op.job_id,
+                machine_id: op.machine_id,
+                start: op.start,
+                finish: op.finish,
+            })
+            .collect();
+
+        let mut machines: Vec<MachineSchedule> = (0..individual.n_machines)
+            .map(|machine_id| MachineSchedule {
+                machine_id,
+                operations: Vec::new(),
+            })
+            .collect();
+
+        let mut by_start = decoded.operations.clone();
+        by_start.sort_by_key(|op| op.start);
+        for op in &by_start {
+            machines[op.machine_id].operations.push(op.operation_id);
+        }
+
+        ScheduleResult {
+            makespan: decoded.makespan,
+            operations,
+            machines,
+        }
+    }
+}
+
+/// Decodes `individual`'s schedule and writes it as JSON to `path`.
+pub fn write_schedule(individual: &JsspIndividual, path: &Path) -> Result<(), String> {
+    let result = ScheduleResult::from(individual);
+    let json = serde_json::to_string_pretty(&result)
+        .map_err(|err| format!("failed to serialize schedule: {err}"))?;
+
+    fs::write(path, json).map_err(|err| format!("failed to write {path:?}: {err}"))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::rc::Rc;
+
+    use super::*;
+    use crate::problem::Operation;
+
+    // Two jobs, two machines: job 0 = [op0 on M0 dur 2, op1 on M1 dur 3],
+    // job 1 = [op2 on M1 dur 4, op3 on M0 dur 1]. Chromosome `[2, 0, 1, 3]`
+    // dispatches op2 and op0 first (both immediately ready), then op1 once
+    // its machine frees up, then op3 last.
+    fn individual() -> JsspIndividual {
+        let operations = Rc::new(vec![
+            Operation {
+                id: 0,
+                job_id: 0,
+                machine_id: 0,
+                duration: 2,
+            },
+            Operation {
+                id: 1,
+                job_id: 0,
+                machine_id: 1,
+                duration: 3,
+            },
+            Operation {
+                id: 2,
+                job_id: 1,
+                machine_id: 1,
+                duration: 4,
+            },
+            Operation {
+                id: 3,
+                job_id: 1,
+                machine_id: 0,
+                duration: 1,
+            },
+        ]);
+
+        JsspIndividual::new(vec![2, 0, 1, 3], operations, 2, 2)
+    }
+
+    #[test]
+    fn from_individual_computes_makespan_and_machine_grouping() {
+        let result = ScheduleResult::from(&individual());
+
+        assert_eq!(result.makespan, 7);
+        assert_eq!(result.machines.len(), 2);
+        assert_eq!(result.machines[0].machine_id, 0);
+        assert_eq!(result.machines[0].operations, vec![0, 3]);
+        assert_eq!(result.machines[1].machine_id, 1);
+        assert_eq!(result.machines[1].operations, vec![2, 1]);
+    }
+
+    #[test]
+    fn json_round_trips_through_serde() {
+        let result = ScheduleResult::from(&individual());
+        let json = serde_json::to_string(&result).unwrap();
+
+        let loaded: ScheduleResult = serde_json::from_str(&json).unwrap();
+
+        assert_eq!(loaded.makespan, result.makespan);
+        assert_eq!(loaded.operations.len(), result.operations.len());
+        assert_eq!(loaded.machines[0].operations, result.machines[0].operations);
+        assert_eq!(loaded.machines[1].operations, result.machines[1].operations);
+    }
+}
@@ -0,0 +1,22 @@
+use std::collections::HashMap;
+use std::fs::File;
+use std::path::Path;
+
+/// Initializes the global logger (`env_logger`, which writes to stderr) and
+/// creates an empty file at `metadata_path`. The targets in `event_map` are
+/// only reported at debug level; events are not routed to the files it
+/// names, and nothing is written into `metadata_path`.
+pub fn init_logging(
+    event_map: &HashMap<String, String>,
+    metadata_path: &Path,
+) -> Result<(), Box<dyn std::error::Error>> {
+    env_logger::init();
+
+    for (target, path) in event_map {
+        log::debug!("routing {target} events to {path}");
+    }
+
+    File::create(metadata_path)?;
+
+    Ok(())
+}
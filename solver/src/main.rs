@@ -1,149 +1,126 @@
-#![allow(unused_imports)]
+mod check;
 mod cli;
 mod config;
+mod experiment;
+mod export;
 mod logging;
 mod parse;
 mod problem;
+mod termination;
 mod util;
 
-use std::path::{Path, PathBuf};
-use std::time::Duration;
-
-use cli::Args;
-use config::{Config, SOLVER_TYPE_RANDOMSEARCH, SOLVER_TYPE_CUSTOM_CROSSOVER};
-use ecrs::ga::probe::{AggregatedProbe, ElapsedTime, PolicyDrivenProbe, ProbingPolicy};
-use ecrs::prelude::{crossover, ga, ops, replacement, selection};
-use ecrs::{
-    ga::{GAMetadata, Individual, StdoutProbe},
-    prelude::{
-        crossover::{CrossoverOperator, UniformParameterized},
-        mutation::{self, Identity},
-        replacement::{BothParents, ReplacementOperator},
-        selection::{Rank, SelectionOperator},
-    },
-};
-use log::info;
-use problem::crossover::JsspCrossover;
-use problem::fitness::JsspFitness;
+use cli::{CheckArgs, Command, SolveArgs};
+use config::Config;
+use ecrs::ga::probe::AggregatedProbe;
+use ecrs::prelude::ga;
+use log::{info, warn};
 use problem::individual::JsspIndividual;
 use problem::population::JsspPopProvider;
 use problem::probe::JsspProbe;
-use problem::replacement::JsspReplacement;
 
-use crate::problem::crossover::MidPoint;
-use crate::problem::{JsspConfig, JsspInstance};
+use crate::problem::JsspInstance;
+use crate::termination::CvTerminationProbe;
 
 struct RunConfig {
     pop_size: usize,
     n_gen: usize,
+    cv_termination: CvTerminationProbe,
 }
 
 fn get_run_config(instance: &JsspInstance, config: &Config) -> RunConfig {
     let pop_size = if let Some(ps) = config.pop_size {
-        ps  // Overrided by user
+        ps // Overrided by user
     } else {
-        instance.cfg.n_ops * 2  // Defined in paper
+        instance.cfg.n_ops * 2 // Defined in paper
     };
 
     let n_gen = if let Some(ng) = config.n_gen {
-        ng  // Overrided by user
+        ng // Overrided by user
     } else {
-        400  // Defined in paper
+        400 // Defined in paper
     };
 
-    RunConfig { pop_size, n_gen }
-}
-
-fn run_randomsearch(instance: JsspInstance, config: Config) {
-    info!("Running jssp solver with random search");
-
-    let run_config = get_run_config(&instance, &config);
-
-    // let probe = AggregatedProbe::new()
-    //     .add_probe(JsspProbe::new())
-    //     .add_probe(PolicyDrivenProbe::new(
-    //         ElapsedTime::new(Duration::from_millis(1000), Duration::from_millis(0)),
-    //         StdoutProbe::new(),
-    //     ));
-
-    // Only for debugging purposes. TODO: Remove it
-    // let population_provider = JsspPopProvider::new(instance.clone());
-    // for op in population_provider.operations.iter() {
-    //     info!("{op:?}");
-    // }
+    let cv_termination = match config.min_cv {
+        Some(threshold) => CvTerminationProbe::new(config.cv_window, threshold),
+        None => CvTerminationProbe::disabled(),
+    };
 
-    ga::Builder::new()
-        .set_population_generator(JsspPopProvider::new(instance.clone()))
-        .set_fitness(JsspFitness::new(1.5))
-        .set_selection_operator(problem::selection::EmptySelection::new())
-        .set_crossover_operator(problem::crossover::NoopCrossover::new())
-        .set_mutation_operator(mutation::Identity::new())
-        .set_replacement_operator(problem::replacement::ReplaceWithRandomPopulation::new(
-            JsspPopProvider::new(instance),
-        ))
-        .set_probe(JsspProbe::new())
-        .set_max_generation_count(run_config.n_gen)
-        .set_population_size(run_config.pop_size)
-        .build()
-        .run();
+    RunConfig {
+        pop_size,
+        n_gen,
+        cv_termination,
+    }
 }
 
-fn run_paper_solver(instance: JsspInstance, config: Config) {
-    info!("Running JSSP solver");
-
-    let run_config = get_run_config(&instance, &config);
-
-
-    // let probe = AggregatedProbe::new()
-    //     .add_probe(JsspProbe::new())
-    //     .add_probe(PolicyDrivenProbe::new(
-    //         ElapsedTime::new(Duration::from_millis(1000), Duration::from_millis(0)),
-    //         StdoutProbe::new(),
-    //     ));
+/// Builds the population provider used to seed a run: a warm-start provider
+/// when `--init-solution` was given and is valid, falling back to a plain
+/// random provider otherwise.
+fn build_population_provider(instance: JsspInstance, config: &Config) -> JsspPopProvider {
+    let Some(path) = &config.init_solution else {
+        return JsspPopProvider::new(instance);
+    };
 
-    // Only for debugging purposes. TODO: Remove it
-    // let population_provider = JsspPopProvider::new(instance.clone());
-    // for op in population_provider.operations.iter() {
-    //     info!("{op:?}");
-    // }
+    let seed = match problem::population::load_seed_chromosome(path) {
+        Ok(seed) => seed,
+        Err(err) => {
+            warn!("could not load warm-start solution {path:?} ({err}); falling back to a random population");
+            return JsspPopProvider::new(instance);
+        }
+    };
 
-    ga::Builder::new()
-        .set_selection_operator(selection::Rank::new())
-        .set_crossover_operator(JsspCrossover::new())
-        .set_mutation_operator(mutation::Identity::new())
-        .set_population_generator(JsspPopProvider::new(instance.clone()))
-        .set_replacement_operator(JsspReplacement::new(JsspPopProvider::new(instance), 0.1, 0.2))
-        .set_fitness(JsspFitness::new(1.5))
-        .set_probe(JsspProbe::new())
-        // .set_max_duration(std::time::Duration::from_secs(30))
-        .set_max_generation_count(run_config.n_gen)
-        .set_population_size(run_config.pop_size)
-        .build()
-        .run();
+    match JsspPopProvider::with_seed(instance.clone(), seed, config.init_size) {
+        Ok(provider) => provider,
+        Err(err) => {
+            warn!("warm-start solution {path:?} is invalid ({err}); falling back to a random population");
+            JsspPopProvider::new(instance)
+        }
+    }
 }
 
-fn run_paper_solver_with_custom_operators(instance: JsspInstance, config: Config) {
-    info!("Running jssp solver with custom operators");
+/// Builds and runs a solver from a declarative [`experiment::ExperimentConfig`]:
+/// the operator combination and their numeric parameters come entirely from
+/// the config, whether it was read from `--config` or looked up as a
+/// [`experiment::built_in`] preset for `--solver-type`. This is the solver's
+/// only builder path; there are no more hardcoded per-solver-type functions.
+fn run_solver(
+    instance: JsspInstance,
+    config: Config,
+    experiment: experiment::ExperimentConfig,
+) -> JsspIndividual {
+    info!("Running solver built from experiment config");
 
     let run_config = get_run_config(&instance, &config);
 
+    let selection = experiment::build_selection(&experiment.selection)
+        .unwrap_or_else(|err| panic!("invalid experiment config: {err}"));
+    let crossover = experiment::build_crossover(&experiment.crossover)
+        .unwrap_or_else(|err| panic!("invalid experiment config: {err}"));
+    let mutation = experiment::build_mutation(&experiment.mutation)
+        .unwrap_or_else(|err| panic!("invalid experiment config: {err}"));
+    let replacement = experiment::build_replacement(&experiment.replacement, &instance)
+        .unwrap_or_else(|err| panic!("invalid experiment config: {err}"));
+    let fitness = experiment::build_fitness(&experiment.fitness)
+        .unwrap_or_else(|err| panic!("invalid experiment config: {err}"));
+
+    let probe = AggregatedProbe::new()
+        .add_probe(JsspProbe::new())
+        .add_probe(run_config.cv_termination);
+
     ga::Builder::new()
-        .set_selection_operator(selection::Rank::new())
-        .set_crossover_operator(MidPoint::new())
-        .set_mutation_operator(mutation::Identity::new())
-        .set_population_generator(JsspPopProvider::new(instance.clone()))
-        .set_replacement_operator(JsspReplacement::new(JsspPopProvider::new(instance), 0.1, 0.2))
-        .set_fitness(JsspFitness::new(1.5))
-        .set_probe(JsspProbe::new())
-        // .set_max_duration(std::time::Duration::from_secs(30))
+        .set_population_generator(build_population_provider(instance.clone(), &config))
+        .set_selection_operator(selection)
+        .set_crossover_operator(crossover)
+        .set_mutation_operator(mutation)
+        .set_replacement_operator(replacement)
+        .set_fitness(fitness)
+        .set_probe(probe)
         .set_max_generation_count(run_config.n_gen)
         .set_population_size(run_config.pop_size)
         .build()
-        .run();
+        .run()
 }
 
-fn run() {
-    let args = cli::parse_args();
+fn run_solve(args: SolveArgs) {
     let config = match Config::try_from(args) {
         Ok(config) => config,
         Err(err) => panic!("Failed to create config from args: {err}"),
@@ -152,21 +129,80 @@ fn run() {
     util::assert_dir_exists(config.output_dir.as_ref());
     let event_map = util::create_event_map(config.output_dir.as_ref());
 
-    if let Err(err) = logging::init_logging(&event_map, &config.output_dir.join("run_metadata.json")) {
+    if let Err(err) =
+        logging::init_logging(&event_map, &config.output_dir.join("run_metadata.json"))
+    {
         panic!("Logger initialization failed with error: {err}");
     }
 
     // Existance of input file is asserted during cli args parsing
-    let instance = JsspInstance::try_from(&config.input_file).unwrap();
+    let instance = parse::parse_instance(&config.input_file, config.format)
+        .unwrap_or_else(|err| panic!("Failed to parse instance: {err}"));
+
+    let out_result = config.out_result.clone();
+
+    let experiment = config
+        .experiment
+        .clone()
+        .unwrap_or_else(|| experiment::built_in(&config.solver_type));
+    let best = run_solver(instance, config, experiment);
+
+    if let Some(out_result) = out_result {
+        if let Err(err) = export::write_schedule(&best, &out_result) {
+            panic!("Failed to write result schedule: {err}");
+        }
+    }
+}
 
-    match config.solver_type.as_str() {
-        SOLVER_TYPE_RANDOMSEARCH => run_randomsearch(instance, config),
-        SOLVER_TYPE_CUSTOM_CROSSOVER => run_paper_solver_with_custom_operators(instance, config),
-        _ => run_paper_solver(instance, config),
+/// Validates a schedule file against an instance without running the GA;
+/// prints a per-constraint violation report and returns whether the
+/// schedule is feasible.
+fn run_check(args: CheckArgs) -> bool {
+    let instance = match parse::parse_instance(&args.input_file, args.format) {
+        Ok(instance) => instance,
+        Err(err) => {
+            eprintln!("Failed to parse instance {:?}: {err}", args.input_file);
+            return false;
+        }
+    };
+
+    let schedule = match check::load_schedule(&args.schedule_file) {
+        Ok(schedule) => schedule,
+        Err(err) => {
+            eprintln!("Failed to load schedule {:?}: {err}", args.schedule_file);
+            return false;
+        }
+    };
+
+    let report = check::validate(&instance, &schedule);
+
+    if report.is_feasible() {
+        println!("Schedule is feasible. Makespan: {}", schedule.makespan);
+        true
+    } else {
+        println!(
+            "Schedule is INFEASIBLE ({} violation(s)):",
+            report.violations.len()
+        );
+        for violation in &report.violations {
+            println!("  - {violation}");
+        }
+        false
     }
 }
 
-fn main() -> Result<(), ()> {
-    run();
-    Ok(())
+fn main() -> std::process::ExitCode {
+    match cli::parse_args().command {
+        Command::Solve(args) => {
+            run_solve(args);
+            std::process::ExitCode::SUCCESS
+        }
+        Command::Check(args) => {
+            if run_check(args) {
+                std::process::ExitCode::SUCCESS
+            } else {
+                std::process::ExitCode::FAILURE
+            }
+        }
+    }
 }
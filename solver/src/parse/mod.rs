@@ -0,0 +1,55 @@
+mod standard;
+mod taillard;
+
+use std::fs;
+use std::path::Path;
+
+use crate::problem::JsspInstance;
+
+/// Which JSSP benchmark layout to parse an instance file as.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+pub enum Format {
+    /// Sniff the format from the file's contents.
+    Auto,
+    /// The classic OR-Library layout: interleaved machine/duration pairs.
+    Standard,
+    /// The Taillard layout: separate `Times`/`Machines` matrices.
+    Taillard,
+}
+
+/// Reads and parses the instance file at `path` as `format`, normalizing it
+/// into the solver's internal [`JsspInstance`] representation regardless of
+/// which on-disk layout it came from.
+pub fn parse_instance(path: &Path, format: Format) -> Result<JsspInstance, String> {
+    let content = fs::read_to_string(path)
+        .map_err(|err| format!("failed to read instance file {path:?}: {err}"))?;
+
+    match resolve(format, &content)? {
+        Format::Standard => standard::parse(&content),
+        Format::Taillard => taillard::parse(&content),
+        Format::Auto => unreachable!("resolve() never returns Auto"),
+    }
+}
+
+fn resolve(format: Format, content: &str) -> Result<Format, String> {
+    match format {
+        Format::Auto => sniff(content),
+        explicit => Ok(explicit),
+    }
+}
+
+fn sniff(content: &str) -> Result<Format, String> {
+    if content.trim().is_empty() {
+        return Err(String::from("instance file is empty"));
+    }
+
+    if taillard::looks_like(content) {
+        Ok(Format::Taillard)
+    } else if standard::looks_like(content) {
+        Ok(Format::Standard)
+    } else {
+        Err(String::from(
+            "could not detect instance format; pass --format explicitly",
+        ))
+    }
+}
@@ -0,0 +1,109 @@
+use crate::problem::{JsspConfig, JsspInstance, Operation};
+
+/// Parses the classic OR-Library "standard" JSSP layout: a header line
+/// `n_jobs n_machines` followed by one line per job of `machine_id duration`
+/// pairs, one pair per operation in processing order.
+pub fn parse(content: &str) -> Result<JsspInstance, String> {
+    let mut lines = content.lines().filter(|line| !line.trim().is_empty());
+
+    let header = lines
+        .next()
+        .ok_or_else(|| String::from("instance file is empty"))?;
+    let mut header_fields = header.split_whitespace();
+    let n_jobs: usize = header_fields
+        .next()
+        .ok_or_else(|| String::from("missing job count in header"))?
+        .parse()
+        .map_err(|_| String::from("job count in header is not a number"))?;
+    let n_machines: usize = header_fields
+        .next()
+        .ok_or_else(|| String::from("missing machine count in header"))?
+        .parse()
+        .map_err(|_| String::from("machine count in header is not a number"))?;
+
+    let mut operations = Vec::new();
+    for job_id in 0..n_jobs {
+        let line = lines
+            .next()
+            .ok_or_else(|| format!("missing line for job {job_id}, expected {n_jobs} jobs"))?;
+        let fields: Vec<&str> = line.split_whitespace().collect();
+        if fields.len() != n_machines * 2 {
+            return Err(format!(
+                "job {job_id} line has {} field(s), expected {} ({n_machines} machine/duration pairs)",
+                fields.len(),
+                n_machines * 2
+            ));
+        }
+
+        for pair in fields.chunks_exact(2) {
+            let machine_id: usize = pair[0]
+                .parse()
+                .map_err(|_| format!("invalid machine id in job {job_id}"))?;
+            let duration: usize = pair[1]
+                .parse()
+                .map_err(|_| format!("invalid duration in job {job_id}"))?;
+
+            operations.push(Operation {
+                id: operations.len(),
+                job_id,
+                machine_id,
+                duration,
+            });
+        }
+    }
+
+    let n_ops = operations.len();
+
+    Ok(JsspInstance {
+        cfg: JsspConfig {
+            n_jobs,
+            n_machines,
+            n_ops,
+        },
+        operations,
+    })
+}
+
+/// Whether `content` looks like the "standard" layout: it carries neither
+/// of the `Times`/`Machines` section markers. The header and job lines are
+/// not inspected here; malformed lines are reported by [`parse`].
+pub fn looks_like(content: &str) -> bool {
+    !content.contains("Times") && !content.contains("Machines")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_a_well_formed_instance() {
+        let content = "2 2\n0 1 1 2\n1 3 0 1\n";
+
+        let instance = parse(content).unwrap();
+
+        assert_eq!(instance.cfg.n_jobs, 2);
+        assert_eq!(instance.cfg.n_machines, 2);
+        assert_eq!(instance.cfg.n_ops, 4);
+        assert_eq!(instance.operations[0].machine_id, 0);
+        assert_eq!(instance.operations[0].duration, 1);
+        assert_eq!(instance.operations[1].job_id, 0);
+        assert_eq!(instance.operations[3].machine_id, 0);
+    }
+
+    #[test]
+    fn rejects_a_job_line_with_the_wrong_pair_count() {
+        // Header declares 2 machines per job, but job 0 only supplies one pair.
+        let content = "2 2\n0 1\n1 3 0 1\n";
+
+        let err = parse(content).unwrap_err();
+        assert!(err.contains("job 0"));
+    }
+
+    #[test]
+    fn rejects_a_truncated_file_missing_job_lines() {
+        let content = "2 2\n0 1 1 2\n";
+
+        let err = parse(content).unwrap_err();
+        assert!(err.contains("missing line for job 1"));
+    }
+}
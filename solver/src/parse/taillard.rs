@@ -0,0 +1,149 @@
+use crate::problem::{JsspConfig, JsspInstance, Operation};
+
+/// Parses the Taillard benchmark layout: a `n_jobs n_machines` header, a
+/// `Times` section with one line per job of `n_machines` durations, and a
+/// `Machines` section with one line per job of `n_machines` (1-indexed)
+/// machine ids giving that job's processing order.
+pub fn parse(content: &str) -> Result<JsspInstance, String> {
+    let mut lines = content
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty());
+
+    let header = lines
+        .next()
+        .ok_or_else(|| String::from("instance file is empty"))?;
+    let mut header_fields = header.split_whitespace();
+    let n_jobs: usize = header_fields
+        .next()
+        .ok_or_else(|| String::from("missing job count in header"))?
+        .parse()
+        .map_err(|_| String::from("job count in header is not a number"))?;
+    let n_machines: usize = header_fields
+        .next()
+        .ok_or_else(|| String::from("missing machine count in header"))?
+        .parse()
+        .map_err(|_| String::from("machine count in header is not a number"))?;
+
+    expect_section(&mut lines, "Times")?;
+    let durations = read_matrix(&mut lines, n_jobs, n_machines, "Times")?;
+
+    expect_section(&mut lines, "Machines")?;
+    let machine_orders = read_matrix(&mut lines, n_jobs, n_machines, "Machines")?;
+
+    let mut operations = Vec::with_capacity(n_jobs * n_machines);
+    for job_id in 0..n_jobs {
+        for op_idx in 0..n_machines {
+            // Taillard machine ids are 1-indexed.
+            let machine_id = machine_orders[job_id][op_idx]
+                .checked_sub(1)
+                .ok_or_else(|| {
+                    format!("job {job_id} references machine id 0, expected 1-indexed ids")
+                })?;
+
+            operations.push(Operation {
+                id: operations.len(),
+                job_id,
+                machine_id,
+                duration: durations[job_id][op_idx],
+            });
+        }
+    }
+
+    let n_ops = operations.len();
+
+    Ok(JsspInstance {
+        cfg: JsspConfig {
+            n_jobs,
+            n_machines,
+            n_ops,
+        },
+        operations,
+    })
+}
+
+fn expect_section<'a>(lines: &mut impl Iterator<Item = &'a str>, name: &str) -> Result<(), String> {
+    let marker = lines
+        .next()
+        .ok_or_else(|| format!("missing {name:?} section"))?;
+    if !marker.eq_ignore_ascii_case(name) {
+        return Err(format!(
+            "expected a {name:?} section marker, found {marker:?}"
+        ));
+    }
+    Ok(())
+}
+
+fn read_matrix<'a>(
+    lines: &mut impl Iterator<Item = &'a str>,
+    n_jobs: usize,
+    n_machines: usize,
+    section: &str,
+) -> Result<Vec<Vec<usize>>, String> {
+    let mut rows = Vec::with_capacity(n_jobs);
+    for job_id in 0..n_jobs {
+        let line = lines
+            .next()
+            .ok_or_else(|| format!("missing {section} row for job {job_id}"))?;
+        let row: Vec<usize> = line
+            .split_whitespace()
+            .map(|field| {
+                field
+                    .parse()
+                    .map_err(|_| format!("invalid value in {section} row for job {job_id}"))
+            })
+            .collect::<Result<_, String>>()?;
+
+        if row.len() != n_machines {
+            return Err(format!(
+                "{section} row for job {job_id} has {} values, expected {n_machines}",
+                row.len()
+            ));
+        }
+        rows.push(row);
+    }
+    Ok(rows)
+}
+
+/// Whether `content` looks like the Taillard layout: it carries the
+/// `Times`/`Machines` section markers the standard layout doesn't.
+pub fn looks_like(content: &str) -> bool {
+    content.contains("Times") && content.contains("Machines")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_a_well_formed_instance() {
+        let content = "2 2\nTimes\n1 2\n3 1\nMachines\n1 2\n2 1\n";
+
+        let instance = parse(content).unwrap();
+
+        assert_eq!(instance.cfg.n_jobs, 2);
+        assert_eq!(instance.cfg.n_machines, 2);
+        assert_eq!(instance.cfg.n_ops, 4);
+        // Machine ids are converted from 1-indexed to 0-indexed.
+        assert_eq!(instance.operations[0].machine_id, 0);
+        assert_eq!(instance.operations[0].duration, 1);
+        assert_eq!(instance.operations[1].machine_id, 1);
+        assert_eq!(instance.operations[3].machine_id, 0);
+    }
+
+    #[test]
+    fn rejects_a_missing_section_marker() {
+        let content = "2 2\n1 2\n3 1\nMachines\n1 2\n2 1\n";
+
+        let err = parse(content).unwrap_err();
+        assert!(err.contains("Times"));
+    }
+
+    #[test]
+    fn rejects_a_row_with_the_wrong_length() {
+        let content = "2 2\nTimes\n1 2\n3\nMachines\n1 2\n2 1\n";
+
+        let err = parse(content).unwrap_err();
+        assert!(err.contains("job 1"));
+    }
+}
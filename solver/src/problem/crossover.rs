@@ -0,0 +1,137 @@
+use rand::{thread_rng, Rng};
+
+use ecrs::prelude::crossover::CrossoverOperator;
+
+use super::individual::JsspIndividual;
+
+/// A no-op crossover for the random-search baseline: parents pass through
+/// unchanged, since the random search never mixes solutions.
+pub struct NoopCrossover;
+
+impl NoopCrossover {
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl CrossoverOperator<JsspIndividual> for NoopCrossover {
+    fn apply(
+        &mut self,
+        parent_1: &JsspIndividual,
+        parent_2: &JsspIndividual,
+    ) -> (JsspIndividual, JsspIndividual) {
+        (parent_1.clone(), parent_2.clone())
+    }
+}
+
+/// Order crossover (OX) over the operation permutation: copies a random
+/// slice from one parent and fills the remaining positions with the other
+/// parent's operations, preserving their relative order.
+pub struct JsspCrossover;
+
+impl JsspCrossover {
+    pub fn new() -> Self {
+        Self
+    }
+
+    fn order_crossover(donor: &JsspIndividual, filler: &JsspIndividual) -> JsspIndividual {
+        let len = donor.chromosome.len();
+        let mut rng = thread_rng();
+        let a = rng.gen_range(0..len);
+        let b = rng.gen_range(0..len);
+        let (lo, hi) = (a.min(b), a.max(b));
+
+        let mut child = vec![None; len];
+        child[lo..=hi].copy_from_slice(
+            &donor.chromosome[lo..=hi]
+                .iter()
+                .copied()
+                .map(Some)
+                .collect::<Vec<_>>(),
+        );
+
+        let taken: std::collections::HashSet<usize> =
+            donor.chromosome[lo..=hi].iter().copied().collect();
+        let mut fill_iter = filler.chromosome.iter().filter(|op| !taken.contains(op));
+
+        for slot in child.iter_mut() {
+            if slot.is_none() {
+                *slot = fill_iter.next().copied();
+            }
+        }
+
+        let chromosome = child
+            .into_iter()
+            .map(|op| op.expect("every slot filled during order crossover"))
+            .collect();
+        JsspIndividual::new(
+            chromosome,
+            donor.operations.clone(),
+            donor.n_jobs,
+            donor.n_machines,
+        )
+    }
+}
+
+impl CrossoverOperator<JsspIndividual> for JsspCrossover {
+    fn apply(
+        &mut self,
+        parent_1: &JsspIndividual,
+        parent_2: &JsspIndividual,
+    ) -> (JsspIndividual, JsspIndividual) {
+        (
+            Self::order_crossover(parent_1, parent_2),
+            Self::order_crossover(parent_2, parent_1),
+        )
+    }
+}
+
+/// Single-midpoint crossover: the child takes the first half of its
+/// chromosome from one parent and fills the rest with the other parent's
+/// remaining operations, in the order they appear in that parent. Used by
+/// the `custom-crossover` solver type to contrast with [`JsspCrossover`]'s
+/// order crossover.
+pub struct MidPoint;
+
+impl MidPoint {
+    pub fn new() -> Self {
+        Self
+    }
+
+    fn combine(
+        first_half_from: &JsspIndividual,
+        second_half_from: &JsspIndividual,
+    ) -> JsspIndividual {
+        let len = first_half_from.chromosome.len();
+        let mid = len / 2;
+
+        let mut chromosome = first_half_from.chromosome[..mid].to_vec();
+        let taken: std::collections::HashSet<usize> = chromosome.iter().copied().collect();
+        chromosome.extend(
+            second_half_from
+                .chromosome
+                .iter()
+                .filter(|op| !taken.contains(op)),
+        );
+
+        JsspIndividual::new(
+            chromosome,
+            first_half_from.operations.clone(),
+            first_half_from.n_jobs,
+            first_half_from.n_machines,
+        )
+    }
+}
+
+impl CrossoverOperator<JsspIndividual> for MidPoint {
+    fn apply(
+        &mut self,
+        parent_1: &JsspIndividual,
+        parent_2: &JsspIndividual,
+    ) -> (JsspIndividual, JsspIndividual) {
+        (
+            Self::combine(parent_1, parent_2),
+            Self::combine(parent_2, parent_1),
+        )
+    }
+}
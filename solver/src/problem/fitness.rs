@@ -0,0 +1,117 @@
+use ecrs::prelude::fitness::FitnessOperator;
+
+use super::individual::JsspIndividual;
+
+/// The decoded timing of a single operation within a feasible schedule.
+#[derive(Debug, Clone, Copy)]
+pub struct OperationSchedule {
+    pub operation_id: usize,
+    pub job_id: usize,
+    pub machine_id: usize,
+    pub start: usize,
+    pub finish: usize,
+}
+
+/// The fully decoded schedule for an individual: every operation's timing
+/// plus the resulting makespan.
+#[derive(Debug, Clone)]
+pub struct DecodedSchedule {
+    pub operations: Vec<OperationSchedule>,
+    pub makespan: usize,
+}
+
+/// Priority-list schedule decoding: at each step, among the operations that
+/// are actually ready (their job predecessor, if any, has already been
+/// dispatched), the one with the highest chromosome priority (earliest
+/// position in `individual.chromosome`) is dispatched next. It starts once
+/// both its job predecessor and the last operation dispatched on its machine
+/// have finished; idle gaps on a machine are not backfilled. This
+/// is what makes the chromosome a priority list rather than a literal
+/// dispatch order, so job precedence is always respected regardless of how
+/// the chromosome orders operations from different jobs relative to each
+/// other.
+pub fn decode(individual: &JsspIndividual) -> DecodedSchedule {
+    let operations = &individual.operations;
+
+    // Operations are stored job-major in processing order (see the
+    // `parse` module), so grouping by `job_id` while preserving that order
+    // recovers each job's operation sequence.
+    let mut job_sequence = vec![Vec::new(); individual.n_jobs];
+    for op in operations.iter() {
+        job_sequence[op.job_id].push(op.id);
+    }
+
+    let mut predecessor = vec![None; operations.len()];
+    for sequence in &job_sequence {
+        for pair in sequence.windows(2) {
+            predecessor[pair[1]] = Some(pair[0]);
+        }
+    }
+
+    let mut priority = vec![0usize; operations.len()];
+    for (rank, &op_id) in individual.chromosome.iter().enumerate() {
+        priority[op_id] = rank;
+    }
+
+    let mut job_ready_at = vec![0usize; individual.n_jobs];
+    let mut machine_ready_at = vec![0usize; individual.n_machines];
+    let mut next_in_job = vec![0usize; individual.n_jobs];
+    let mut finished = vec![false; operations.len()];
+    let mut scheduled = Vec::with_capacity(operations.len());
+
+    for _ in 0..operations.len() {
+        let op_id = (0..individual.n_jobs)
+            .filter_map(|job_id| {
+                let op_id = *job_sequence[job_id].get(next_in_job[job_id])?;
+                let ready = predecessor[op_id].map_or(true, |pred| finished[pred]);
+                ready.then_some(op_id)
+            })
+            .min_by_key(|&op_id| priority[op_id])
+            .expect("every job with remaining operations has exactly one ready next operation");
+
+        let op = &operations[op_id];
+        let start = job_ready_at[op.job_id].max(machine_ready_at[op.machine_id]);
+        let finish = start + op.duration;
+
+        job_ready_at[op.job_id] = finish;
+        machine_ready_at[op.machine_id] = finish;
+        next_in_job[op.job_id] += 1;
+        finished[op_id] = true;
+
+        scheduled.push(OperationSchedule {
+            operation_id: op.id,
+            job_id: op.job_id,
+            machine_id: op.machine_id,
+            start,
+            finish,
+        });
+    }
+
+    let makespan = scheduled.iter().map(|op| op.finish).max().unwrap_or(0);
+
+    DecodedSchedule {
+        operations: scheduled,
+        makespan,
+    }
+}
+
+/// Fitness operator for the JSSP: the decoded makespan scaled by a tightness
+/// factor used to bias selection pressure, as in the paper's operator set.
+pub struct JsspFitness {
+    tightness: f64,
+}
+
+impl JsspFitness {
+    pub fn new(tightness: f64) -> Self {
+        Self { tightness }
+    }
+}
+
+impl FitnessOperator<JsspIndividual> for JsspFitness {
+    fn apply(&mut self, individual: &mut JsspIndividual) -> f64 {
+        let schedule = decode(individual);
+        let fitness = schedule.makespan as f64 * self.tightness;
+        individual.fitness = fitness;
+        fitness
+    }
+}
@@ -0,0 +1,53 @@
+use std::rc::Rc;
+
+use ecrs::ga::Individual;
+
+use super::Operation;
+
+/// A JSSP solution encoded as a permutation of operation ids: the dispatch
+/// priority used to break ties when more than one operation is ready to
+/// run (see [`crate::problem::fitness::decode`]). Each individual carries a
+/// shared handle to the instance's operation table so that fitness/decoding
+/// never needs a separate reference to the `JsspInstance`.
+#[derive(Debug, Clone)]
+pub struct JsspIndividual {
+    pub chromosome: Vec<usize>,
+    pub operations: Rc<Vec<Operation>>,
+    pub n_jobs: usize,
+    pub n_machines: usize,
+    pub fitness: f64,
+}
+
+impl JsspIndividual {
+    pub fn new(
+        chromosome: Vec<usize>,
+        operations: Rc<Vec<Operation>>,
+        n_jobs: usize,
+        n_machines: usize,
+    ) -> Self {
+        Self {
+            chromosome,
+            operations,
+            n_jobs,
+            n_machines,
+            fitness: 0.0,
+        }
+    }
+}
+
+impl Individual for JsspIndividual {
+    type ChromosomeT = Vec<usize>;
+    type FitnessValueT = f64;
+
+    fn chromosome(&self) -> &Self::ChromosomeT {
+        &self.chromosome
+    }
+
+    fn get_fitness(&self) -> Self::FitnessValueT {
+        self.fitness
+    }
+
+    fn set_fitness(&mut self, fitness: Self::FitnessValueT) {
+        self.fitness = fitness;
+    }
+}
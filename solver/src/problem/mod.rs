@@ -0,0 +1,34 @@
+pub mod crossover;
+pub mod fitness;
+pub mod individual;
+pub mod population;
+pub mod probe;
+pub mod replacement;
+pub mod selection;
+
+/// Static sizing of a JSSP instance, derived once while parsing.
+#[derive(Debug, Clone, Copy)]
+pub struct JsspConfig {
+    pub n_jobs: usize,
+    pub n_machines: usize,
+    pub n_ops: usize,
+}
+
+/// A single operation of job `job_id`, identified by its instance-wide `id`,
+/// to be processed on `machine_id` for `duration` time units.
+#[derive(Debug, Clone, Copy)]
+pub struct Operation {
+    pub id: usize,
+    pub job_id: usize,
+    pub machine_id: usize,
+    pub duration: usize,
+}
+
+/// An instance is only ever built by [`crate::parse::parse_instance`], which
+/// both `solve` and `check` call directly with the user's `--format`; there
+/// is deliberately no second, format-blind construction path.
+#[derive(Debug, Clone)]
+pub struct JsspInstance {
+    pub cfg: JsspConfig,
+    pub operations: Vec<Operation>,
+}
@@ -0,0 +1,159 @@
+use std::fs;
+use std::path::Path;
+use std::rc::Rc;
+
+use rand::seq::SliceRandom;
+use rand::{thread_rng, Rng};
+
+use ecrs::prelude::population::PopulationGenerator;
+
+use super::individual::JsspIndividual;
+use super::{JsspInstance, Operation};
+
+/// Generates starting populations for the JSSP GA.
+///
+/// By default every individual is a uniformly random permutation of
+/// operation ids. Optionally a warm-start seed individual can be attached
+/// via [`JsspPopProvider::with_seed`]; `seed_count` copies/perturbations of
+/// it are then injected into every generated population, with the remainder
+/// filled randomly as usual.
+pub struct JsspPopProvider {
+    operations: Rc<Vec<Operation>>,
+    n_jobs: usize,
+    n_machines: usize,
+    seed: Option<JsspIndividual>,
+    seed_count: usize,
+}
+
+impl JsspPopProvider {
+    pub fn new(instance: JsspInstance) -> Self {
+        Self {
+            operations: Rc::new(instance.operations),
+            n_jobs: instance.cfg.n_jobs,
+            n_machines: instance.cfg.n_machines,
+            seed: None,
+            seed_count: 0,
+        }
+    }
+
+    /// Builds a provider that seeds `seed_count` individuals of every
+    /// generated population from `seed`, filling the rest randomly.
+    ///
+    /// `seed`'s chromosome must reference exactly the operations of
+    /// `instance` (same length, every operation id present exactly once);
+    /// callers should fall back to [`JsspPopProvider::new`] on error.
+    pub fn with_seed(
+        instance: JsspInstance,
+        seed: Vec<usize>,
+        seed_count: usize,
+    ) -> Result<Self, String> {
+        Self::validate_seed(&instance, &seed)?;
+
+        let operations = Rc::new(instance.operations);
+        let seed = JsspIndividual::new(
+            seed,
+            Rc::clone(&operations),
+            instance.cfg.n_jobs,
+            instance.cfg.n_machines,
+        );
+
+        Ok(Self {
+            operations,
+            n_jobs: instance.cfg.n_jobs,
+            n_machines: instance.cfg.n_machines,
+            seed: Some(seed),
+            seed_count,
+        })
+    }
+
+    fn validate_seed(instance: &JsspInstance, seed: &[usize]) -> Result<(), String> {
+        let n_ops = instance.cfg.n_ops;
+        if seed.len() != n_ops {
+            return Err(format!(
+                "seed solution has {} operations, instance has {n_ops}",
+                seed.len()
+            ));
+        }
+
+        let mut seen = vec![false; n_ops];
+        for &op_id in seed {
+            if op_id >= n_ops {
+                return Err(format!("seed references unknown operation id {op_id}"));
+            }
+            if seen[op_id] {
+                return Err(format!("seed references operation {op_id} more than once"));
+            }
+            seen[op_id] = true;
+        }
+
+        Ok(())
+    }
+
+    fn random_individual(&self) -> JsspIndividual {
+        let mut chromosome: Vec<usize> = (0..self.operations.len()).collect();
+        chromosome.shuffle(&mut thread_rng());
+        JsspIndividual::new(
+            chromosome,
+            Rc::clone(&self.operations),
+            self.n_jobs,
+            self.n_machines,
+        )
+    }
+
+    /// A perturbation of the seed (a handful of random swaps), so the
+    /// injected copies aren't all identical to one another.
+    fn perturbed_seed(&self, seed: &JsspIndividual) -> JsspIndividual {
+        let mut chromosome = seed.chromosome.clone();
+        let mut rng = thread_rng();
+        let n_swaps = (chromosome.len() / 20).max(1);
+        for _ in 0..n_swaps {
+            let i = rng.gen_range(0..chromosome.len());
+            let j = rng.gen_range(0..chromosome.len());
+            chromosome.swap(i, j);
+        }
+        JsspIndividual::new(
+            chromosome,
+            Rc::clone(&self.operations),
+            self.n_jobs,
+            self.n_machines,
+        )
+    }
+}
+
+/// Reads a warm-start chromosome: one operation id per line, blank lines
+/// ignored. This is the same encoding `JsspIndividual` uses internally.
+pub fn load_seed_chromosome(path: &Path) -> Result<Vec<usize>, String> {
+    let content = fs::read_to_string(path)
+        .map_err(|err| format!("failed to read warm-start solution {path:?}: {err}"))?;
+
+    content
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty())
+        .map(|line| {
+            line.parse::<usize>()
+                .map_err(|_| format!("invalid operation id {line:?} in warm-start solution"))
+        })
+        .collect()
+}
+
+impl PopulationGenerator<JsspIndividual> for JsspPopProvider {
+    fn generate(&mut self, count: usize) -> Vec<JsspIndividual> {
+        let Some(seed) = self.seed.as_ref() else {
+            return (0..count).map(|_| self.random_individual()).collect();
+        };
+
+        let n_seeded = self.seed_count.min(count);
+        let mut population = Vec::with_capacity(count);
+        if n_seeded > 0 {
+            population.push(seed.clone());
+        }
+        for _ in 1..n_seeded {
+            population.push(self.perturbed_seed(seed));
+        }
+        for _ in n_seeded..count {
+            population.push(self.random_individual());
+        }
+        population
+    }
+}
@@ -0,0 +1,29 @@
+use ecrs::ga::probe::Probe;
+use ecrs::ga::GAMetadata;
+use log::info;
+
+use super::individual::JsspIndividual;
+
+/// Logs the progress of a JSSP run: the best fitness found so far, and a
+/// summary line at the end of the run. This is the event log that every run
+/// produces regardless of which solver variant is used.
+pub struct JsspProbe;
+
+impl JsspProbe {
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl Probe<JsspIndividual> for JsspProbe {
+    fn on_new_best(&mut self, metadata: &GAMetadata, individual: &JsspIndividual) {
+        info!(
+            "generation {}: new best fitness = {}",
+            metadata.generation, individual.fitness
+        );
+    }
+
+    fn on_end(&mut self, metadata: &GAMetadata) {
+        info!("run finished after {} generations", metadata.generation);
+    }
+}
@@ -0,0 +1,81 @@
+use rand::seq::SliceRandom;
+use rand::thread_rng;
+
+use ecrs::prelude::replacement::ReplacementOperator;
+
+use super::individual::JsspIndividual;
+use super::population::JsspPopProvider;
+
+/// Replaces the whole population with a freshly generated one each
+/// generation; pairs with [`super::selection::EmptySelection`] and
+/// [`super::crossover::NoopCrossover`] to implement plain random search.
+pub struct ReplaceWithRandomPopulation {
+    population_provider: JsspPopProvider,
+}
+
+impl ReplaceWithRandomPopulation {
+    pub fn new(population_provider: JsspPopProvider) -> Self {
+        Self {
+            population_provider,
+        }
+    }
+}
+
+impl ReplacementOperator<JsspIndividual> for ReplaceWithRandomPopulation {
+    fn apply(
+        &mut self,
+        _population: Vec<JsspIndividual>,
+        _children: Vec<JsspIndividual>,
+    ) -> Vec<JsspIndividual> {
+        self.population_provider.generate(_population.len())
+    }
+}
+
+/// Elitist replacement with fresh-blood injection: keeps the best
+/// `elitism_rate` fraction of the current population, fills another
+/// `immigration_rate` fraction with brand-new random individuals, and the
+/// remainder with children, as used by the paper's solver configuration.
+pub struct JsspReplacement {
+    population_provider: JsspPopProvider,
+    elitism_rate: f64,
+    immigration_rate: f64,
+}
+
+impl JsspReplacement {
+    pub fn new(
+        population_provider: JsspPopProvider,
+        elitism_rate: f64,
+        immigration_rate: f64,
+    ) -> Self {
+        Self {
+            population_provider,
+            elitism_rate,
+            immigration_rate,
+        }
+    }
+}
+
+impl ReplacementOperator<JsspIndividual> for JsspReplacement {
+    fn apply(
+        &mut self,
+        mut population: Vec<JsspIndividual>,
+        mut children: Vec<JsspIndividual>,
+    ) -> Vec<JsspIndividual> {
+        let target_size = population.len();
+
+        population.sort_by(|a, b| a.fitness.partial_cmp(&b.fitness).unwrap());
+        let n_elite = ((target_size as f64) * self.elitism_rate).round() as usize;
+        let n_immigrants = ((target_size as f64) * self.immigration_rate).round() as usize;
+
+        let mut next_population: Vec<JsspIndividual> =
+            population.into_iter().take(n_elite).collect();
+        next_population.extend(self.population_provider.generate(n_immigrants));
+
+        let mut rng = thread_rng();
+        children.shuffle(&mut rng);
+        let remaining = target_size.saturating_sub(next_population.len());
+        next_population.extend(children.into_iter().take(remaining));
+
+        next_population
+    }
+}
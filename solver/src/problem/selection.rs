@@ -0,0 +1,22 @@
+use ecrs::prelude::selection::SelectionOperator;
+
+use super::individual::JsspIndividual;
+
+/// A no-op selection operator for the random-search baseline: the first `n`
+/// individuals are carried forward unchanged and fitness is not looked at.
+/// No selection pressure is applied anywhere in random search;
+/// [`super::replacement::ReplaceWithRandomPopulation`] discards the
+/// population each generation.
+pub struct EmptySelection;
+
+impl EmptySelection {
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl SelectionOperator<JsspIndividual> for EmptySelection {
+    fn apply(&mut self, population: &[JsspIndividual], n: usize) -> Vec<JsspIndividual> {
+        population.iter().take(n).cloned().collect()
+    }
+}
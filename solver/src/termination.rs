@@ -0,0 +1,179 @@
+use std::collections::VecDeque;
+
+use ecrs::ga::probe::Probe;
+use ecrs::ga::{GAMetadata, Individual};
+
+/// A probe that requests the GA to stop once the population's best fitness
+/// has plateaued.
+///
+/// It keeps a fixed-size ring buffer of the best fitness seen so far,
+/// sampled once per generation (not only on improvement, so a plateau of
+/// repeated identical values actually drives the statistic towards zero).
+/// Once the buffer is full, it computes the coefficient of variation
+/// `cv = stddev / mean` over the buffer; when `cv` drops below `threshold`
+/// the run is considered converged and
+/// [`should_terminate`](Probe::should_terminate) starts returning `true`.
+pub struct CvTerminationProbe {
+    window: usize,
+    threshold: Option<f64>,
+    history: VecDeque<f64>,
+    last_best: Option<f64>,
+}
+
+impl CvTerminationProbe {
+    pub fn new(window: usize, threshold: f64) -> Self {
+        assert!(window > 0, "cv termination window must be non-zero");
+        Self {
+            window,
+            threshold: Some(threshold),
+            history: VecDeque::with_capacity(window),
+            last_best: None,
+        }
+    }
+
+    /// Builds a probe that never requests termination; used when the user
+    /// did not opt into the convergence criterion, so callers can always
+    /// attach a `CvTerminationProbe` without branching on configuration.
+    pub fn disabled() -> Self {
+        Self {
+            window: 1,
+            threshold: None,
+            history: VecDeque::new(),
+            last_best: None,
+        }
+    }
+
+    fn coefficient_of_variation(&self) -> Option<f64> {
+        if self.history.len() < self.window {
+            return None;
+        }
+
+        let mean = self.history.iter().sum::<f64>() / self.window as f64;
+        if mean == 0.0 {
+            return Some(0.0);
+        }
+
+        let variance = self
+            .history
+            .iter()
+            .map(|value| (value - mean).powi(2))
+            .sum::<f64>()
+            / self.window as f64;
+
+        Some(variance.sqrt() / mean.abs())
+    }
+
+    fn record_best(&mut self, best: f64) {
+        self.last_best = Some(best);
+    }
+
+    /// Samples the last known best fitness into the ring buffer and
+    /// evaluates the termination criterion. Split out from
+    /// [`should_terminate`](Probe::should_terminate) so the sampling/cv
+    /// logic can be unit-tested without needing a real `GAMetadata`.
+    fn sample_and_check(&mut self) -> bool {
+        if let Some(best) = self.last_best {
+            if self.history.len() == self.window {
+                self.history.pop_front();
+            }
+            self.history.push_back(best);
+        }
+
+        let Some(threshold) = self.threshold else {
+            return false;
+        };
+
+        self.coefficient_of_variation()
+            .map(|cv| cv < threshold)
+            .unwrap_or(false)
+    }
+}
+
+impl<T: Individual<FitnessValueT = f64>> Probe<T> for CvTerminationProbe {
+    fn on_new_best(&mut self, _metadata: &GAMetadata, individual: &T) {
+        self.record_best(individual.get_fitness());
+    }
+
+    fn should_terminate(&mut self, _metadata: &GAMetadata) -> bool {
+        // Called once per generation regardless of whether this generation
+        // improved on the best, so this is where we sample: otherwise a
+        // plateau (the common case once the population converges) would
+        // never fill the window and the criterion would never fire.
+        self.sample_and_check()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn disabled_probe_never_terminates() {
+        let mut probe = CvTerminationProbe::disabled();
+
+        for _ in 0..1000 {
+            probe.record_best(42.0);
+            assert!(!probe.sample_and_check());
+        }
+    }
+
+    #[test]
+    fn does_not_terminate_before_the_window_fills() {
+        let mut probe = CvTerminationProbe::new(5, 0.1);
+
+        for _ in 0..4 {
+            probe.record_best(10.0);
+            assert!(!probe.sample_and_check());
+        }
+    }
+
+    #[test]
+    fn terminates_once_a_plateau_fills_the_window() {
+        let mut probe = CvTerminationProbe::new(5, 0.1);
+
+        // A constant best fitness plateau: cv should hit 0 once the
+        // 5-sample window is full, well below the 0.1 threshold.
+        let mut terminated = false;
+        for _ in 0..5 {
+            probe.record_best(100.0);
+            terminated = probe.sample_and_check();
+        }
+
+        assert!(terminated);
+    }
+
+    #[test]
+    fn does_not_terminate_while_fitness_keeps_improving() {
+        let mut probe = CvTerminationProbe::new(5, 0.1);
+
+        // Each sample is far from the others, so cv stays well above the
+        // threshold as long as the population keeps improving.
+        let mut terminated = false;
+        for best in [100.0, 80.0, 60.0, 40.0, 20.0] {
+            probe.record_best(best);
+            terminated = probe.sample_and_check();
+        }
+
+        assert!(!terminated);
+    }
+
+    #[test]
+    fn evicts_the_oldest_sample_once_the_window_is_full() {
+        let mut probe = CvTerminationProbe::new(3, 0.1);
+
+        probe.record_best(1.0);
+        probe.sample_and_check();
+        probe.record_best(2.0);
+        probe.sample_and_check();
+        probe.record_best(3.0);
+        probe.sample_and_check();
+        assert_eq!(probe.history, vec![1.0, 2.0, 3.0]);
+
+        // A 4th sample should push out the oldest (1.0), not grow the
+        // buffer past its configured window.
+        probe.record_best(4.0);
+        probe.sample_and_check();
+        assert_eq!(probe.history.len(), 3);
+        assert_eq!(probe.history, vec![2.0, 3.0, 4.0]);
+    }
+}
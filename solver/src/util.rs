@@ -0,0 +1,20 @@
+use std::collections::HashMap;
+use std::path::Path;
+
+/// Ensures `dir` exists, creating it (and any missing parents) if needed.
+pub fn assert_dir_exists(dir: &Path) {
+    if !dir.exists() {
+        std::fs::create_dir_all(dir).expect("failed to create output directory");
+    }
+}
+
+/// Builds the map from logger target name to the file each event stream is
+/// written to, rooted under `output_dir`.
+pub fn create_event_map(output_dir: &Path) -> HashMap<String, String> {
+    let mut event_map = HashMap::new();
+    event_map.insert(
+        String::from("event"),
+        output_dir.join("event.log").to_string_lossy().into_owned(),
+    );
+    event_map
+}